@@ -1,13 +1,22 @@
 use anyhow::{anyhow, Result};
+use bytes::{Bytes, BytesMut};
 use clap::{Parser, Subcommand};
 use dirs::home_dir;
+use flate2::read::GzDecoder;
+use futures_util::StreamExt;
+use indicatif::{ProgressBar, ProgressStyle};
 use reqwest::Client;
+use schemars::JsonSchema;
+use semver::{Version, VersionReq};
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use std::collections::HashMap;
 use std::fs;
-use std::io::{self, Write};
-use std::path::PathBuf;
+use std::io::{self, Cursor, Read, Write};
+use std::path::{Path, PathBuf};
+use tar::Archive;
 use tokio::fs as tokio_fs;
+use zip::ZipArchive;
 
 #[derive(Parser)]
 #[command(name = "tl")]
@@ -19,17 +28,34 @@ struct Cli {
 
 #[derive(Subcommand)]
 enum Commands {
-    Install { tool_name: String },
+    Install {
+        tool_name: String,
+        /// GitHub personal access token, for private repos or rate-limit relief.
+        /// Falls back to the GITHUB_TOKEN/GH_TOKEN environment variables.
+        #[arg(long)]
+        token: Option<String>,
+    },
     Uninstall { tool_name: String },
     List,
+    Update {
+        tool_name: Option<String>,
+        /// GitHub personal access token, for private repos or rate-limit relief.
+        /// Falls back to the GITHUB_TOKEN/GH_TOKEN environment variables.
+        #[arg(long)]
+        token: Option<String>,
+    },
+    /// Print the JSON Schema for tools.json registry files.
+    Schema,
+    /// Validate a tools.json registry file.
+    Validate { path: Option<PathBuf> },
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, JsonSchema)]
 struct ToolRegistry {
     tools: HashMap<String, Tool>,
 }
 
-#[derive(Serialize, Deserialize, Clone)]
+#[derive(Serialize, Deserialize, Clone, JsonSchema)]
 struct Tool {
     name: String,
     description: String,
@@ -37,6 +63,29 @@ struct Tool {
     install_method: String,
     binary_name: String,
     install_path: String,
+    /// Pins the expected SHA-256 of the release asset, taking precedence
+    /// over any checksum file published alongside the release.
+    #[serde(default)]
+    expected_sha256: Option<String>,
+    /// Semver requirement (e.g. ">=1.2, <2.0") constraining which release
+    /// tags are eligible for install/update. Tags are matched after
+    /// stripping a leading `v`. Unset means "always take the newest".
+    #[serde(default)]
+    version: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Default)]
+struct InstallState {
+    #[serde(default)]
+    tools: HashMap<String, InstalledTool>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+struct InstalledTool {
+    binary_path: String,
+    source_repo: String,
+    version: String,
+    installed_at: u64,
 }
 
 #[tokio::main]
@@ -44,8 +93,8 @@ async fn main() -> Result<()> {
     let cli = Cli::parse();
 
     match cli.command {
-        Commands::Install { tool_name } => {
-            install_tool(&tool_name).await?;
+        Commands::Install { tool_name, token } => {
+            install_tool(&tool_name, token).await?;
         }
         Commands::Uninstall { tool_name } => {
             uninstall_tool(&tool_name).await?;
@@ -53,6 +102,15 @@ async fn main() -> Result<()> {
         Commands::List => {
             list_tools().await?;
         }
+        Commands::Update { tool_name, token } => {
+            update_tool(tool_name, token).await?;
+        }
+        Commands::Schema => {
+            print_schema()?;
+        }
+        Commands::Validate { path } => {
+            validate_registry(path)?;
+        }
     }
 
     Ok(())
@@ -85,7 +143,138 @@ fn get_registry_path() -> Result<PathBuf> {
     Err(anyhow!("Registry file not found. Looked for tools.json in current directory and project root."))
 }
 
-async fn install_tool(tool_name: &str) -> Result<()> {
+/// Prints the JSON Schema for `tools.json` registry files, generated from
+/// the `ToolRegistry`/`Tool` types, so editors can offer validation and
+/// completion when authoring a registry.
+fn print_schema() -> Result<()> {
+    let schema = schemars::schema_for!(ToolRegistry);
+    println!("{}", serde_json::to_string_pretty(&schema)?);
+    Ok(())
+}
+
+/// Loads a `tools.json` registry (from `path`, or the usual lookup when
+/// unset) and reports unknown install methods, empty binary names,
+/// malformed repo strings and invalid version requirements with actionable
+/// messages, rather than letting them fail deep inside `install_tool`.
+/// Missing or mistyped fields are reported through serde_json's parse
+/// error, prefixed with the file path; they get no message of their own.
+fn validate_registry(path: Option<PathBuf>) -> Result<()> {
+    let path = match path {
+        Some(path) => path,
+        None => get_registry_path()?,
+    };
+
+    let content = fs::read_to_string(&path)
+        .map_err(|e| anyhow!("Failed to read {}: {}", path.display(), e))?;
+    let registry: ToolRegistry = serde_json::from_str(&content)
+        .map_err(|e| anyhow!("Failed to parse {}: {}", path.display(), e))?;
+
+    let mut errors = Vec::new();
+    for (name, tool) in &registry.tools {
+        if tool.install_method != "github_release" {
+            errors.push(format!(
+                "tool '{}': unknown install_method '{}' (expected 'github_release')",
+                name, tool.install_method
+            ));
+        }
+        if tool.binary_name.trim().is_empty() {
+            errors.push(format!("tool '{}': binary_name must not be empty", name));
+        }
+        if tool.github_repo.split('/').filter(|part| !part.is_empty()).count() != 2 {
+            errors.push(format!(
+                "tool '{}': github_repo '{}' must be in 'owner/repo' form",
+                name, tool.github_repo
+            ));
+        }
+        if let Some(version) = &tool.version {
+            if VersionReq::parse(version).is_err() {
+                errors.push(format!(
+                    "tool '{}': version '{}' is not a valid semver requirement",
+                    name, version
+                ));
+            }
+        }
+    }
+
+    if errors.is_empty() {
+        println!("✅ {} is valid ({} tools)", path.display(), registry.tools.len());
+        return Ok(());
+    }
+
+    for error in &errors {
+        eprintln!("❌ {}", error);
+    }
+    Err(anyhow!("{} has {} validation error(s)", path.display(), errors.len()))
+}
+
+/// Resolves the path to the install-state manifest, following the
+/// XDG/ProjectDirs convention of `~/.local/state/<app>/installed.json`.
+fn get_state_path() -> Result<PathBuf> {
+    let home = home_dir().ok_or_else(|| anyhow!("Could not determine home directory"))?;
+    Ok(home.join(".local").join("state").join("tl").join("installed.json"))
+}
+
+fn load_install_state() -> Result<InstallState> {
+    let state_path = get_state_path()?;
+    if !state_path.exists() {
+        return Ok(InstallState::default());
+    }
+    let content = fs::read_to_string(state_path)?;
+    let state: InstallState = serde_json::from_str(&content)?;
+    Ok(state)
+}
+
+fn save_install_state(state: &InstallState) -> Result<()> {
+    let state_path = get_state_path()?;
+    if let Some(parent) = state_path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let content = serde_json::to_string_pretty(state)?;
+    fs::write(state_path, content)?;
+    Ok(())
+}
+
+/// Resolves the GitHub token to use for API/asset requests: the `--token`
+/// flag takes precedence, falling back to the GITHUB_TOKEN/GH_TOKEN env vars.
+fn resolve_github_token(token_arg: Option<String>) -> Option<String> {
+    token_arg
+        .or_else(|| std::env::var("GITHUB_TOKEN").ok())
+        .or_else(|| std::env::var("GH_TOKEN").ok())
+}
+
+/// Builds a spinner with `message`, ticking steadily until finished.
+fn new_spinner(message: &str) -> ProgressBar {
+    let pb = ProgressBar::new_spinner();
+    pb.set_style(
+        ProgressStyle::with_template("{spinner:.green} {msg}")
+            .unwrap(),
+    );
+    pb.enable_steady_tick(std::time::Duration::from_millis(100));
+    pb.set_message(message.to_string());
+    pb
+}
+
+/// Runs `f` under a spinner labeled `message`, finishing it with a
+/// success or failure message depending on the outcome.
+fn with_spinner<T>(message: &str, f: impl FnOnce() -> Result<T>) -> Result<T> {
+    let pb = new_spinner(message);
+    let result = f();
+    match &result {
+        Ok(_) => pb.finish_with_message(format!("✅ {}", message)),
+        Err(err) => pb.finish_with_message(format!("❌ {}: {}", message, err)),
+    }
+    result
+}
+
+fn unix_timestamp() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+async fn install_tool(tool_name: &str, token: Option<String>) -> Result<()> {
+    let token = resolve_github_token(token);
     let registry = load_tool_registry().await?;
     
     let tool = registry.tools.get(tool_name)
@@ -114,59 +303,162 @@ async fn install_tool(tool_name: &str) -> Result<()> {
     println!("📦 Installing {}...", tool.name);
     println!("   {}", tool.description);
 
-    match tool.install_method.as_str() {
-        "github_release" => {
-            install_from_github_release(tool).await?;
-        }
+    let version = match tool.install_method.as_str() {
+        "github_release" => install_from_github_release(tool, token.as_deref()).await?,
         _ => {
             return Err(anyhow!("Unsupported install method: {}", tool.install_method));
         }
+    };
+
+    let mut state = load_install_state()?;
+    state.tools.insert(
+        tool_name.to_string(),
+        InstalledTool {
+            binary_path: binary_path.to_string_lossy().to_string(),
+            source_repo: tool.github_repo.clone(),
+            version: display_version(&version),
+            installed_at: unix_timestamp(),
+        },
+    );
+    save_install_state(&state)?;
+
+    Ok(())
+}
+
+async fn update_tool(tool_name: Option<String>, token: Option<String>) -> Result<()> {
+    let token = resolve_github_token(token);
+    let registry = load_tool_registry().await?;
+    let state = load_install_state()?;
+
+    let names: Vec<String> = match tool_name {
+        Some(name) => vec![name],
+        None => {
+            let mut names: Vec<String> = state.tools.keys().cloned().collect();
+            names.sort();
+            names
+        }
+    };
+
+    if names.is_empty() {
+        println!("No tools are installed.");
+        return Ok(());
+    }
+
+    for name in names {
+        if let Err(err) = update_one_tool(&name, &registry, token.as_deref()).await {
+            eprintln!("❌ Failed to update {}: {}", name, err);
+        }
     }
 
     Ok(())
 }
 
-async fn install_from_github_release(tool: &Tool) -> Result<()> {
+async fn update_one_tool(tool_name: &str, registry: &ToolRegistry, token: Option<&str>) -> Result<()> {
+    let tool = registry.tools.get(tool_name)
+        .ok_or_else(|| anyhow!("Tool '{}' not found in registry", tool_name))?;
+
+    let mut state = load_install_state()?;
+    let installed = match state.tools.get(tool_name) {
+        Some(installed) => installed.clone(),
+        None => {
+            println!("ℹ️  {} is not installed", tool_name);
+            return Ok(());
+        }
+    };
+
+    println!("🔄 Checking updates for {}...", tool.name);
+
     let client = Client::new();
-    let api_url = format!("https://api.github.com/repos/{}/releases/latest", tool.github_repo);
-    
-    println!("🔍 Fetching latest release information...");
-    let response = client
-        .get(&api_url)
-        .header("User-Agent", "tl-tool-installer")
-        .send()
-        .await?;
+    let releases = fetch_releases(&client, &tool.github_repo, token).await?;
+    let newest_release = select_release(&releases, tool.version.as_deref())?;
+    let newest_tag = newest_release["tag_name"].as_str()
+        .ok_or_else(|| anyhow!("No tag name found in release"))?;
+    let newest_version_display = display_version(newest_tag);
+
+    let installed_version = parse_semver_tag(&installed.version);
+    let newest_version = parse_semver_tag(newest_tag);
 
-    if !response.status().is_success() {
-        return Err(anyhow!("Failed to fetch release info: {}", response.status()));
+    if let (Some(installed_version), Some(newest_version)) = (&installed_version, &newest_version) {
+        if installed_version >= newest_version {
+            println!("✅ {} already up to date (v{})", tool.name, installed.version);
+            return Ok(());
+        }
+    } else if installed.version == newest_version_display {
+        println!("✅ {} already up to date (v{})", tool.name, installed.version);
+        return Ok(());
     }
 
-    let release: serde_json::Value = response.json().await?;
+    println!("⬆️  Updating {} from v{} to v{}...", tool.name, installed.version, newest_version_display);
+    let version = install_from_github_release(tool, token).await?;
+
+    let install_path = expand_install_path(&tool.install_path)?;
+    let binary_path = install_path.join(&tool.binary_name);
+
+    state.tools.insert(
+        tool_name.to_string(),
+        InstalledTool {
+            binary_path: binary_path.to_string_lossy().to_string(),
+            source_repo: tool.github_repo.clone(),
+            version: display_version(&version),
+            installed_at: unix_timestamp(),
+        },
+    );
+    save_install_state(&state)?;
+
+    Ok(())
+}
+
+async fn install_from_github_release(tool: &Tool, token: Option<&str>) -> Result<String> {
+    let client = Client::new();
+
+    let spinner = new_spinner("Fetching release information...");
+    let releases = match fetch_releases(&client, &tool.github_repo, token).await {
+        Ok(releases) => {
+            spinner.finish_with_message("✅ Fetched release information");
+            releases
+        }
+        Err(err) => {
+            spinner.finish_with_message(format!("❌ Failed to fetch release information: {}", err));
+            return Err(err);
+        }
+    };
+
+    let release = select_release(&releases, tool.version.as_deref())?;
+    let tag_name = release["tag_name"].as_str()
+        .ok_or_else(|| anyhow!("No tag name found in release"))?
+        .to_string();
     let assets = release["assets"].as_array()
         .ok_or_else(|| anyhow!("No assets found in release"))?;
 
     // Find the appropriate asset for the current platform
     let platform = get_platform_string();
     let asset = find_platform_asset(assets, &platform)?;
-    
-    let download_url = asset["browser_download_url"].as_str()
-        .ok_or_else(|| anyhow!("No download URL found"))?;
 
-    println!("⬇️  Downloading binary...");
-    
-    // Download the binary
-    let response = client.get(download_url).send().await?;
-    let bytes = response.bytes().await?;
+    let asset_name = asset["name"].as_str()
+        .ok_or_else(|| anyhow!("No asset name found"))?
+        .to_string();
+
+    let bytes = download_asset(&client, asset, token).await?;
+
+    verify_checksum(&client, tool, assets, &asset_name, &bytes, token).await?;
 
     // Determine install path
     let install_path = expand_install_path(&tool.install_path)?;
     fs::create_dir_all(&install_path)?;
-    
+
     let binary_path = install_path.join(&tool.binary_name);
-    
-    // Write the binary
-    fs::write(&binary_path, bytes)?;
-    
+
+    // Write the binary, extracting it from an archive if the asset is one
+    with_spinner("Extracting binary...", || {
+        if asset_name.ends_with(".tar.gz") || asset_name.ends_with(".tgz") {
+            extract_tar_gz(&bytes, tool, &binary_path)
+        } else if asset_name.ends_with(".zip") {
+            extract_zip(&bytes, tool, &binary_path)
+        } else {
+            fs::write(&binary_path, &bytes).map_err(Into::into)
+        }
+    })?;
+
     // Make it executable
     #[cfg(unix)]
     {
@@ -189,9 +481,314 @@ async fn install_from_github_release(tool: &Tool) -> Result<()> {
         println!("  export PATH=\"$PATH:{}\"", install_path.display());
     }
 
+    Ok(tag_name)
+}
+
+/// Releases per page to request (GitHub's maximum), and a cap on the
+/// number of pages to walk so a runaway repo can't make this loop forever.
+/// 10 pages * 100 releases covers any realistically maintained repo; a
+/// constraint that only matches something older than that is not supported.
+const RELEASES_PER_PAGE: u32 = 100;
+const MAX_RELEASES_PAGES: u32 = 10;
+
+/// Fetches the list of releases for a repo (not just `/releases/latest`),
+/// up to `MAX_RELEASES_PAGES` pages, so callers can pick the newest release
+/// satisfying a semver constraint.
+async fn fetch_releases(client: &Client, github_repo: &str, token: Option<&str>) -> Result<Vec<serde_json::Value>> {
+    let mut all_releases = Vec::new();
+
+    for page in 1..=MAX_RELEASES_PAGES {
+        let api_url = format!(
+            "https://api.github.com/repos/{}/releases?per_page={}&page={}",
+            github_repo, RELEASES_PER_PAGE, page
+        );
+
+        let mut request = client
+            .get(&api_url)
+            .header("User-Agent", "tl-tool-installer");
+        if let Some(token) = token {
+            request = request.header("Authorization", format!("Bearer {}", token));
+        }
+        let response = request.send().await?;
+
+        if !response.status().is_success() {
+            return Err(anyhow!("Failed to fetch release info: {}", response.status()));
+        }
+
+        let page_releases: Vec<serde_json::Value> = response.json().await?;
+        let page_len = page_releases.len();
+        all_releases.extend(page_releases);
+
+        if page_len < RELEASES_PER_PAGE as usize {
+            break;
+        }
+    }
+
+    Ok(all_releases)
+}
+
+/// Parses a release tag into a semver `Version`, stripping a leading `v`.
+fn parse_semver_tag(tag: &str) -> Option<Version> {
+    Version::parse(tag.trim_start_matches('v')).ok()
+}
+
+/// Strips a release tag's conventional leading `v` once, for storing and
+/// displaying a version (e.g. "v1.2.3" -> "1.2.3"). Callers that format
+/// with a literal `v` prefix (`"installed v{}"`) must use this, not the
+/// raw tag, or the output doubles up as "vv1.2.3".
+fn display_version(tag: &str) -> String {
+    tag.strip_prefix('v').unwrap_or(tag).to_string()
+}
+
+/// Selects the newest release whose tag satisfies `version_req` (a semver
+/// requirement string like ">=1.2, <2.0"), or, when no requirement is given,
+/// the first non-draft, non-prerelease release in list order, whatever its tag.
+fn select_release<'a>(releases: &'a [serde_json::Value], version_req: Option<&str>) -> Result<&'a serde_json::Value> {
+    let eligible: Vec<&serde_json::Value> = releases
+        .iter()
+        .filter(|release| {
+            !release["draft"].as_bool().unwrap_or(false) && !release["prerelease"].as_bool().unwrap_or(false)
+        })
+        .collect();
+
+    match version_req {
+        Some(v) => {
+            // A constraint needs comparable versions, so only semver-parseable
+            // tags are eligible here.
+            let req = VersionReq::parse(v).map_err(|e| anyhow!("Invalid version requirement '{}': {}", v, e))?;
+            eligible
+                .into_iter()
+                .filter_map(|release| {
+                    let tag = release["tag_name"].as_str()?;
+                    let version = parse_semver_tag(tag)?;
+                    if req.matches(&version) {
+                        Some((version, release))
+                    } else {
+                        None
+                    }
+                })
+                .max_by(|a, b| a.0.cmp(&b.0))
+                .map(|(_, release)| release)
+                .ok_or_else(|| anyhow!("No release found matching version requirement '{}'", v))
+        }
+        None => {
+            // With nothing to constrain by, match GitHub's own `/releases/latest`
+            // semantics: take the newest non-draft, non-prerelease release by
+            // list order, regardless of whether its tag parses as semver (tags
+            // like `nightly` or `build-42` are common and shouldn't fail install).
+            eligible
+                .into_iter()
+                .next()
+                .ok_or_else(|| anyhow!("No non-draft, non-prerelease release found"))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn parse_semver_tag_strips_leading_v() {
+        assert_eq!(parse_semver_tag("v1.2.3"), Some(Version::new(1, 2, 3)));
+        assert_eq!(parse_semver_tag("1.2.3"), Some(Version::new(1, 2, 3)));
+        assert_eq!(parse_semver_tag("not-a-version"), None);
+    }
+
+    #[test]
+    fn display_version_strips_leading_v_once() {
+        assert_eq!(display_version("v1.2.3"), "1.2.3");
+        assert_eq!(display_version("1.2.3"), "1.2.3");
+        assert_eq!(display_version("nightly"), "nightly");
+    }
+
+    #[test]
+    fn select_release_with_constraint_picks_newest_matching_version() {
+        let releases = vec![
+            json!({"tag_name": "v1.0.0", "draft": false, "prerelease": false}),
+            json!({"tag_name": "v1.5.0", "draft": false, "prerelease": false}),
+            json!({"tag_name": "v2.0.0", "draft": false, "prerelease": false}),
+        ];
+
+        let selected = select_release(&releases, Some(">=1.0, <2.0")).unwrap();
+        assert_eq!(selected["tag_name"], "v1.5.0");
+    }
+
+    #[test]
+    fn select_release_without_constraint_takes_first_eligible_in_list_order() {
+        // GitHub's `/releases` endpoint returns releases newest-first, so
+        // without a constraint the first eligible entry is the "latest" one.
+        let releases = vec![
+            json!({"tag_name": "v2.0.0", "draft": false, "prerelease": false}),
+            json!({"tag_name": "v1.0.0", "draft": false, "prerelease": false}),
+        ];
+
+        let selected = select_release(&releases, None).unwrap();
+        assert_eq!(selected["tag_name"], "v2.0.0");
+    }
+
+    #[test]
+    fn select_release_without_constraint_accepts_non_semver_tags() {
+        let releases = vec![json!({"tag_name": "nightly", "draft": false, "prerelease": false})];
+
+        let selected = select_release(&releases, None).unwrap();
+        assert_eq!(selected["tag_name"], "nightly");
+    }
+
+    #[test]
+    fn select_release_skips_drafts_and_prereleases() {
+        let releases = vec![
+            json!({"tag_name": "v2.0.0", "draft": true, "prerelease": false}),
+            json!({"tag_name": "v3.0.0", "draft": false, "prerelease": true}),
+            json!({"tag_name": "v1.0.0", "draft": false, "prerelease": false}),
+        ];
+
+        let selected = select_release(&releases, None).unwrap();
+        assert_eq!(selected["tag_name"], "v1.0.0");
+    }
+}
+
+/// Downloads a release asset's bytes, using the token-authenticated API
+/// endpoint when a token is available (required for private-repo assets)
+/// and the public browser URL otherwise.
+async fn download_asset(client: &Client, asset: &serde_json::Value, token: Option<&str>) -> Result<Bytes> {
+    let mut request = match token {
+        Some(token) => {
+            let asset_url = asset["url"].as_str()
+                .ok_or_else(|| anyhow!("No asset API URL found"))?;
+            client
+                .get(asset_url)
+                .header("Authorization", format!("Bearer {}", token))
+                .header("Accept", "application/octet-stream")
+        }
+        None => {
+            let download_url = asset["browser_download_url"].as_str()
+                .ok_or_else(|| anyhow!("No download URL found"))?;
+            client.get(download_url)
+        }
+    };
+    request = request.header("User-Agent", "tl-tool-installer");
+    let response = request.send().await?;
+
+    let pb = match response.content_length() {
+        Some(size) => {
+            let pb = ProgressBar::new(size);
+            pb.set_style(
+                ProgressStyle::with_template(
+                    "⬇️  {bar:40.cyan/blue} {bytes}/{total_bytes} ({eta})",
+                )
+                .unwrap()
+                .progress_chars("=> "),
+            );
+            pb
+        }
+        None => {
+            let pb = ProgressBar::new_spinner();
+            pb.set_style(ProgressStyle::with_template("⬇️  {spinner:.green} {bytes} downloaded").unwrap());
+            pb.enable_steady_tick(std::time::Duration::from_millis(100));
+            pb
+        }
+    };
+
+    let mut downloaded = BytesMut::new();
+    let mut stream = response.bytes_stream();
+    while let Some(chunk) = stream.next().await {
+        let chunk = chunk?;
+        downloaded.extend_from_slice(&chunk);
+        pb.set_position(downloaded.len() as u64);
+    }
+    pb.finish_with_message("✅ Download complete");
+
+    Ok(downloaded.freeze())
+}
+
+/// Verifies the downloaded asset's SHA-256 against an expected digest,
+/// preferring `tool.expected_sha256` and falling back to a companion
+/// checksum asset (`<asset>.sha256`, `checksums.txt`, or `SHA256SUMS`)
+/// published alongside the release.
+async fn verify_checksum(
+    client: &Client,
+    tool: &Tool,
+    assets: &[serde_json::Value],
+    asset_name: &str,
+    bytes: &[u8],
+    token: Option<&str>,
+) -> Result<()> {
+    let expected = match &tool.expected_sha256 {
+        Some(digest) => Some(digest.to_lowercase()),
+        None => match find_checksum_asset(assets, asset_name) {
+            Some(checksum_asset) => {
+                let checksum_bytes = download_asset(client, checksum_asset, token).await?;
+                let checksum_text = String::from_utf8_lossy(&checksum_bytes);
+                parse_expected_checksum(&checksum_text, asset_name)
+            }
+            None => None,
+        },
+    };
+
+    let Some(expected) = expected else {
+        return Ok(());
+    };
+
+    let mut hasher = Sha256::new();
+    hasher.update(bytes);
+    let actual = format!("{:x}", hasher.finalize());
+
+    if actual != expected {
+        return Err(anyhow!(
+            "Checksum mismatch for {}: expected {}, got {}",
+            asset_name, expected, actual
+        ));
+    }
+
     Ok(())
 }
 
+/// Finds a release asset that looks like a checksum file for `asset_name`.
+fn find_checksum_asset<'a>(assets: &'a [serde_json::Value], asset_name: &str) -> Option<&'a serde_json::Value> {
+    let sha_suffixed = format!("{}.sha256", asset_name);
+    assets.iter().find(|asset| {
+        matches!(asset["name"].as_str(), Some(name) if name == sha_suffixed)
+    }).or_else(|| {
+        assets.iter().find(|asset| {
+            matches!(asset["name"].as_str(), Some(name) if name == "checksums.txt" || name == "SHA256SUMS")
+        })
+    })
+}
+
+/// True for asset names recognized as checksum sidecar files rather than
+/// the installable asset itself (`<asset>.sha256`, `checksums.txt`, `SHA256SUMS`).
+fn is_checksum_asset_name(name: &str) -> bool {
+    name.ends_with(".sha256") || name == "checksums.txt" || name == "SHA256SUMS"
+}
+
+/// Parses a checksum file's contents for the digest matching `asset_name`.
+/// Supports both a bare-digest file (`<asset>.sha256`) and a multi-line
+/// `sha256sum`-style file (`<digest>  <filename>` per line).
+fn parse_expected_checksum(checksum_text: &str, asset_name: &str) -> Option<String> {
+    for line in checksum_text.lines() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        let mut parts = line.split_whitespace();
+        let digest = match parts.next() {
+            Some(digest) => digest,
+            None => continue,
+        };
+        match parts.next() {
+            Some(name) if name.trim_start_matches('*') == asset_name => {
+                return Some(digest.to_lowercase());
+            }
+            // A bare-digest file with no filename column.
+            None => return Some(digest.to_lowercase()),
+            _ => continue,
+        }
+    }
+    None
+}
+
 fn get_platform_string() -> String {
     let os = std::env::consts::OS;
     let arch = std::env::consts::ARCH;
@@ -207,27 +804,38 @@ fn get_platform_string() -> String {
 }
 
 fn find_platform_asset<'a>(assets: &'a [serde_json::Value], platform: &str) -> Result<&'a serde_json::Value> {
+    // Checksum sidecars (e.g. `<asset>.sha256`) carry the same platform
+    // substring as the asset they check, so they must be excluded up front
+    // or they can outrank the real asset depending on upload order.
+    let candidates: Vec<&serde_json::Value> = assets
+        .iter()
+        .filter(|asset| match asset["name"].as_str() {
+            Some(name) => !is_checksum_asset_name(name),
+            None => true,
+        })
+        .collect();
+
     // Try to find exact match first
-    for asset in assets {
+    for &asset in &candidates {
         if let Some(name) = asset["name"].as_str() {
             if name.contains(platform) {
                 return Ok(asset);
             }
         }
     }
-    
+
     // If no exact match, try partial matches
     let os = std::env::consts::OS;
-    for asset in assets {
+    for &asset in &candidates {
         if let Some(name) = asset["name"].as_str() {
             if name.contains(os) {
                 return Ok(asset);
             }
         }
     }
-    
+
     // If no platform-specific asset found, try to find a generic binary
-    for asset in assets {
+    for &asset in &candidates {
         if let Some(name) = asset["name"].as_str() {
             // Check if it's likely a binary (no extension or common binary extensions)
             if !name.contains('.') || name.ends_with(".exe") || name.ends_with(".bin") {
@@ -235,15 +843,150 @@ fn find_platform_asset<'a>(assets: &'a [serde_json::Value], platform: &str) -> R
             }
         }
     }
-    
-    // If still nothing found, just take the first asset
-    if !assets.is_empty() {
-        return Ok(&assets[0]);
+
+    // If still nothing found, just take the first candidate
+    if !candidates.is_empty() {
+        return Ok(candidates[0]);
     }
-    
+
     Err(anyhow!("No assets found in release"))
 }
 
+#[cfg(test)]
+mod find_platform_asset_tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn ignores_checksum_sidecar_with_same_platform_substring() {
+        let assets = vec![
+            json!({"name": "tool-linux-amd64.tar.gz.sha256"}),
+            json!({"name": "tool-linux-amd64.tar.gz"}),
+        ];
+
+        let asset = find_platform_asset(&assets, "linux-amd64").unwrap();
+        assert_eq!(asset["name"], "tool-linux-amd64.tar.gz");
+    }
+
+    #[test]
+    fn ignores_checksums_txt_and_shasums_sidecars() {
+        let assets = vec![
+            json!({"name": "checksums.txt"}),
+            json!({"name": "SHA256SUMS"}),
+            json!({"name": "tool-linux-amd64.tar.gz"}),
+        ];
+
+        let asset = find_platform_asset(&assets, "linux-amd64").unwrap();
+        assert_eq!(asset["name"], "tool-linux-amd64.tar.gz");
+    }
+}
+
+/// Extracts the entry matching `tool.binary_name` (or the sole executable
+/// entry) from a `.tar.gz`/`.tgz` archive and writes it to `binary_path`.
+fn extract_tar_gz(bytes: &[u8], tool: &Tool, binary_path: &Path) -> Result<()> {
+    let decoder = GzDecoder::new(bytes);
+    let mut archive = Archive::new(decoder);
+
+    let mut fallback: Option<(String, Vec<u8>)> = None;
+    let mut fallback_count = 0;
+
+    for entry in archive.entries()? {
+        let mut entry = entry?;
+        let entry_path = entry.path()?.to_path_buf();
+        let entry_name = match entry_path.file_name().and_then(|n| n.to_str()) {
+            Some(name) => name.to_string(),
+            None => continue,
+        };
+
+        if !entry.header().entry_type().is_file() {
+            continue;
+        }
+
+        if entry_name == tool.binary_name {
+            let mut contents = Vec::new();
+            entry.read_to_end(&mut contents)?;
+            fs::write(binary_path, contents)?;
+            return Ok(());
+        }
+
+        if is_likely_executable_name(&entry_name) {
+            fallback_count += 1;
+            if fallback.is_none() {
+                let mut contents = Vec::new();
+                entry.read_to_end(&mut contents)?;
+                fallback = Some((entry_name, contents));
+            }
+        }
+    }
+
+    match fallback {
+        Some((_, contents)) if fallback_count == 1 => {
+            fs::write(binary_path, contents)?;
+            Ok(())
+        }
+        None => Err(anyhow!(
+            "Could not find binary '{}' inside the downloaded archive",
+            tool.binary_name
+        )),
+    }
+}
+
+/// Extracts the entry matching `tool.binary_name` (or the sole executable
+/// entry) from a `.zip` archive and writes it to `binary_path`.
+fn extract_zip(bytes: &[u8], tool: &Tool, binary_path: &Path) -> Result<()> {
+    let mut archive = ZipArchive::new(Cursor::new(bytes))?;
+
+    let mut fallback_index: Option<usize> = None;
+    let mut fallback_count = 0;
+
+    for i in 0..archive.len() {
+        let file = archive.by_index(i)?;
+        if !file.is_file() {
+            continue;
+        }
+        let entry_name = match Path::new(file.name()).file_name().and_then(|n| n.to_str()) {
+            Some(name) => name.to_string(),
+            None => continue,
+        };
+
+        if entry_name == tool.binary_name {
+            drop(file);
+            let mut file = archive.by_index(i)?;
+            let mut contents = Vec::new();
+            file.read_to_end(&mut contents)?;
+            fs::write(binary_path, contents)?;
+            return Ok(());
+        }
+
+        if is_likely_executable_name(&entry_name) {
+            fallback_count += 1;
+            if fallback_index.is_none() {
+                fallback_index = Some(i);
+            }
+        }
+    }
+
+    match fallback_index {
+        Some(i) if fallback_count == 1 => {
+            let mut file = archive.by_index(i)?;
+            let mut contents = Vec::new();
+            file.read_to_end(&mut contents)?;
+            fs::write(binary_path, contents)?;
+            Ok(())
+        }
+        None => Err(anyhow!(
+            "Could not find binary '{}' inside the downloaded archive",
+            tool.binary_name
+        )),
+    }
+}
+
+/// Heuristic used when an archive entry doesn't exactly match `binary_name`:
+/// treat extensionless files (or `.exe`) as the likely executable.
+fn is_likely_executable_name(name: &str) -> bool {
+    !name.contains('.') || name.ends_with(".exe")
+}
+
 fn is_in_path(directory: &PathBuf) -> Result<bool> {
     let path_env = std::env::var("PATH").unwrap_or_default();
     let paths: Vec<&str> = path_env.split(':').collect();
@@ -302,7 +1045,12 @@ async fn uninstall_tool(tool_name: &str) -> Result<()> {
 
     // Remove the binary
     fs::remove_file(&binary_path)?;
-    
+
+    // Drop the tool from the install-state manifest
+    let mut state = load_install_state()?;
+    state.tools.remove(tool_name);
+    save_install_state(&state)?;
+
     println!("✅ Successfully uninstalled {} from {}", tool.name, binary_path.display());
 
     Ok(())
@@ -310,11 +1058,19 @@ async fn uninstall_tool(tool_name: &str) -> Result<()> {
 
 async fn list_tools() -> Result<()> {
     let registry = load_tool_registry().await?;
-    
+    let state = load_install_state()?;
+
     println!("📋 Available tools:");
     for (name, tool) in &registry.tools {
-        println!("  🔧 {} - {}", name, tool.description);
+        match state.tools.get(name) {
+            Some(installed) => {
+                println!("  🔧 {} (installed v{}) - {}", name, installed.version, tool.description);
+            }
+            None => {
+                println!("  ⬜ {} - {}", name, tool.description);
+            }
+        }
     }
-    
+
     Ok(())
 }